@@ -1,3 +1,4 @@
+pub use snob::csets::CharacterSet;
 pub use snob::{csets, Scanner};
 
 pub const EXAMPLE_LIST_PROGRAM: &str = r"
@@ -38,6 +39,9 @@ pub const EXAMPLE_LIST_PROGRAM: &str = r"
 	  (- (rewind-count rewindable) (rewind-index rewindable) 1)))
 ";
 
+// The payload of each variant is only ever read through the `Debug` impl
+// below, which the compiler's dead-code analysis doesn't see through.
+#[allow(dead_code)]
 #[derive(Debug)]
 enum Token {
 	Dot,
@@ -67,7 +71,8 @@ impl Iterator for Tokenizer {
 
 	fn next(&mut self) -> Option<Self::Item> {
 		// skip over any whitespace
-		if let Some(position) = self.scanner.many(" \t\r\n") {
+		let whitespace = ' '.union('\t').union('\r').union('\n');
+		if let Some(position) = self.scanner.many(whitespace) {
 			self.scanner.goto(position);
 		}
 
@@ -97,14 +102,24 @@ impl Iterator for Tokenizer {
 			Some(Token::Integer(number))
 		} else if let Some(position) = self.scanner.any(';') {
 			self.scanner.goto(position);
-			let position = self.scanner.upto("\r\n").expect("Unterminated comment");
+			let position = self
+				.scanner
+				.upto('\r'.union('\n'))
+				.expect("Unterminated comment");
 			let comment = self.scanner.goto(position).unwrap();
 			Some(Token::Comment(comment))
 		} else {
-			let position = self
-				.scanner
-				.upto(" \t\r\n().\"'#")
-				.expect("unterminated symbol");
+			let symbol_boundary = ' '
+				.union('\t')
+				.union('\r')
+				.union('\n')
+				.union('(')
+				.union(')')
+				.union('.')
+				.union('"')
+				.union('\'')
+				.union('#');
+			let position = self.scanner.upto(symbol_boundary).expect("unterminated symbol");
 			let symbol = self.scanner.goto(position).unwrap();
 			Some(Token::Symbol(symbol))
 		}
@@ -21,7 +21,7 @@ pub trait CharacterSet {
 	/// # Example
 	///
 	/// ```
-	/// use snob::csets::AsciiLetters;
+	/// use snob::csets::{AsciiLetters, CharacterSet};
 	///
 	/// assert!(AsciiLetters.contains('h'));
 	/// assert!(!AsciiLetters.contains(' '));
@@ -34,7 +34,7 @@ pub trait CharacterSet {
 	/// # Example
 	///
 	/// ```
-	/// use snob::csets::AsciiLetters;
+	/// use snob::csets::{AsciiLetters, CharacterSet};
 	///
 	/// let cset = AsciiLetters.union(' ');
 	/// assert!(cset.contains('h'));
@@ -56,7 +56,7 @@ pub trait CharacterSet {
 	/// # Example
 	///
 	/// ```
-	/// use snob::csets::AsciiLetters;
+	/// use snob::csets::{AsciiLetters, CharacterSet};
 	///
 	/// let cset = AsciiLetters.intersection("Hello, world");
 	/// assert!(cset.contains('e'));
@@ -82,9 +82,9 @@ pub trait CharacterSet {
 	/// # Example
 	///
 	/// ```
-	/// use snob::csets::AsciiLetters;
+	/// use snob::csets::{AsciiLetters, CharacterSet};
 	///
-	/// let cset = AsciiLetters.intersection("Hello, world");
+	/// let cset = AsciiLetters.difference("Hello, world");
 	/// assert!(cset.contains('a'));
 	/// assert!(!cset.contains('e'));
 	/// assert!(!cset.contains(' '));
@@ -105,7 +105,7 @@ pub trait CharacterSet {
 	/// # Example
 	///
 	/// ```
-	/// use snob::csets::AsciiLetters;
+	/// use snob::csets::{AsciiLetters, CharacterSet};
 	///
 	/// let cset = AsciiLetters.complement();
 	/// assert!(!cset.contains('a'));
@@ -117,6 +117,39 @@ pub trait CharacterSet {
 	{
 		CharacterSetComplement { inner: self }
 	}
+
+	/// Compiles this [`CharacterSet`] into a [`CompiledSet`], flattening
+	/// whatever tree of combinators it's built from into a single bitmap
+	/// lookup for ASCII characters, so that a hot loop like
+	/// [`Scanner::many`](crate::Scanner::many) can test membership with one
+	/// bit check instead of walking the tree on every character.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::csets::{AsciiDigits, CharacterSet};
+	///
+	/// let cset = AsciiDigits.union(' ').compile();
+	/// assert!(cset.contains('4'));
+	/// assert!(cset.contains(' '));
+	/// assert!(!cset.contains('x'));
+	/// ```
+	fn compile(self) -> CompiledSet
+	where
+		Self: Sized + 'static,
+	{
+		let mut ascii = [0u64; 2];
+		for byte in 0u8..128 {
+			if self.contains(byte as char) {
+				ascii[(byte >> 6) as usize] |= 1 << (byte & 63);
+			}
+		}
+
+		CompiledSet {
+			ascii,
+			other: Box::new(move |ch| self.contains(ch)),
+		}
+	}
 }
 
 /// Contains all Unicode characters
@@ -179,6 +212,77 @@ impl CharacterSet for AsciiLetters {
 	}
 }
 
+/// An inclusive range of characters, from `.0` to `.1`.
+///
+/// # Example
+///
+/// ```
+/// use snob::csets::{CharRange, CharacterSet};
+///
+/// let digits = CharRange('0', '9');
+/// assert!(digits.contains('5'));
+/// assert!(!digits.contains('a'));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CharRange(pub char, pub char);
+
+impl CharacterSet for CharRange {
+	fn contains(&self, ch: char) -> bool {
+		self.0 <= ch && ch <= self.1
+	}
+}
+
+/// Contains every Unicode alphanumeric character, per
+/// [`char::is_alphanumeric`].
+#[derive(Debug, Clone, Copy)]
+pub struct Alphanumeric;
+
+impl CharacterSet for Alphanumeric {
+	fn contains(&self, ch: char) -> bool {
+		ch.is_alphanumeric()
+	}
+}
+
+/// Contains every Unicode whitespace character, per [`char::is_whitespace`].
+#[derive(Debug, Clone, Copy)]
+pub struct Whitespace;
+
+impl CharacterSet for Whitespace {
+	fn contains(&self, ch: char) -> bool {
+		ch.is_whitespace()
+	}
+}
+
+/// Contains every Unicode alphabetic character, per [`char::is_alphabetic`].
+#[derive(Debug, Clone, Copy)]
+pub struct Alphabetic;
+
+impl CharacterSet for Alphabetic {
+	fn contains(&self, ch: char) -> bool {
+		ch.is_alphabetic()
+	}
+}
+
+/// Contains every Unicode numeric character, per [`char::is_numeric`].
+#[derive(Debug, Clone, Copy)]
+pub struct Numeric;
+
+impl CharacterSet for Numeric {
+	fn contains(&self, ch: char) -> bool {
+		ch.is_numeric()
+	}
+}
+
+/// Contains every Unicode control character, per [`char::is_control`].
+#[derive(Debug, Clone, Copy)]
+pub struct Control;
+
+impl CharacterSet for Control {
+	fn contains(&self, ch: char) -> bool {
+		ch.is_control()
+	}
+}
+
 impl CharacterSet for char {
 	fn contains(&self, ch: char) -> bool {
 		*self == ch
@@ -203,6 +307,12 @@ impl CharacterSet for HashSet<char> {
 	}
 }
 
+impl<F: Fn(char) -> bool> CharacterSet for F {
+	fn contains(&self, ch: char) -> bool {
+		self(ch)
+	}
+}
+
 /// A union of two [`CharacterSet`]s.
 ///
 /// This is created by calling [`CharacterSet::union`].
@@ -261,3 +371,30 @@ impl<Inner: CharacterSet> CharacterSet for CharacterSetComplement<Inner> {
 		!self.inner.contains(ch)
 	}
 }
+
+/// A [`CharacterSet`] compiled for fast repeated membership tests.
+///
+/// ASCII characters are tested against a 128-bit bitmap; anything outside
+/// the ASCII range falls back to the original set's predicate.
+///
+/// This is created by calling [`CharacterSet::compile`].
+pub struct CompiledSet {
+	ascii: [u64; 2],
+	other: Box<dyn Fn(char) -> bool>,
+}
+
+impl CompiledSet {
+	fn ascii_contains(&self, byte: u8) -> bool {
+		self.ascii[(byte >> 6) as usize] & (1 << (byte & 63)) != 0
+	}
+}
+
+impl CharacterSet for CompiledSet {
+	fn contains(&self, ch: char) -> bool {
+		if ch.is_ascii() {
+			self.ascii_contains(ch as u8)
+		} else {
+			(self.other)(ch)
+		}
+	}
+}
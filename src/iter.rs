@@ -0,0 +1,130 @@
+use crate::csets::CharacterSet;
+use crate::location::Span;
+use crate::pattern::Pattern;
+
+/// An iterator over the non-overlapping matches of a [`Pattern`] in the
+/// remainder of a [`Scanner`](crate::Scanner), yielding the matched
+/// substrings.
+///
+/// This is created by [`Scanner::matches`](crate::Scanner::matches). See its
+/// documentation for more details.
+pub struct Matches<'a, P: Pattern> {
+	pub(crate) source: &'a [char],
+	pub(crate) pattern: P,
+	pub(crate) position: usize,
+}
+
+impl<P: Pattern> Iterator for Matches<'_, P> {
+	type Item = String;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = self.pattern.find_in(self.source, self.position)?;
+		let end = self.pattern.is_prefix_of(self.source, start)?;
+		self.position = end.max(start + 1);
+		Some(self.source[start..end].iter().collect())
+	}
+}
+
+/// An iterator over the non-overlapping matches of a [`Pattern`] in the
+/// remainder of a [`Scanner`](crate::Scanner), yielding the [`Span`] of each
+/// match alongside the matched substring.
+///
+/// This is created by
+/// [`Scanner::match_indices`](crate::Scanner::match_indices). See its
+/// documentation for more details.
+pub struct MatchIndices<'a, P: Pattern> {
+	pub(crate) source: &'a [char],
+	pub(crate) pattern: P,
+	pub(crate) position: usize,
+}
+
+impl<P: Pattern> Iterator for MatchIndices<'_, P> {
+	type Item = (Span, String);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = self.pattern.find_in(self.source, self.position)?;
+		let end = self.pattern.is_prefix_of(self.source, start)?;
+		self.position = end.max(start + 1);
+		Some((Span::new(start, end), self.source[start..end].iter().collect()))
+	}
+}
+
+/// An iterator over the substrings of the remainder of a
+/// [`Scanner`](crate::Scanner) that are separated by runs of characters in a
+/// [`CharacterSet`], yielding the [`Span`] of each substring alongside the
+/// substring itself.
+///
+/// This is created by [`Scanner::split`](crate::Scanner::split). See its
+/// documentation for more details.
+pub struct Split<'a, C: CharacterSet> {
+	pub(crate) source: &'a [char],
+	pub(crate) cset: C,
+	pub(crate) position: Option<usize>,
+}
+
+impl<C: CharacterSet> Iterator for Split<'_, C> {
+	type Item = (Span, String);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = self.position?;
+
+		let mut end = start;
+		while end < self.source.len() && !self.cset.contains(self.source[end]) {
+			end += 1;
+		}
+
+		if end >= self.source.len() {
+			self.position = None;
+		} else {
+			let mut next_start = end;
+			while next_start < self.source.len() && self.cset.contains(self.source[next_start]) {
+				next_start += 1;
+			}
+			self.position = Some(next_start);
+		}
+
+		Some((Span::new(start, end), self.source[start..end].iter().collect()))
+	}
+}
+
+/// An iterator over the lines of the remainder of a
+/// [`Scanner`](crate::Scanner), split on `\r\n` or `\n`, yielding the
+/// [`Span`] of each line alongside the line itself.
+///
+/// This is created by [`Scanner::lines`](crate::Scanner::lines). See its
+/// documentation for more details.
+pub struct Lines<'a> {
+	pub(crate) source: &'a [char],
+	pub(crate) position: Option<usize>,
+}
+
+impl Iterator for Lines<'_> {
+	type Item = (Span, String);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = self.position?;
+		if start >= self.source.len() {
+			self.position = None;
+			return None;
+		}
+
+		match self.source[start..].iter().position(|&ch| ch == '\n') {
+			Some(offset) => {
+				let mut end = start + offset;
+				if end > start && self.source[end - 1] == '\r' {
+					end -= 1;
+				}
+
+				self.position = Some(start + offset + 1);
+				Some((Span::new(start, end), self.source[start..end].iter().collect()))
+			}
+			None => {
+				self.position = None;
+				Some((
+					Span::new(start, self.source.len()),
+					self.source[start..].iter().collect(),
+				))
+			}
+		}
+	}
+}
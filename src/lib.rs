@@ -0,0 +1,9 @@
+pub mod csets;
+pub mod iter;
+pub mod location;
+pub mod partial;
+pub mod pattern;
+mod scanner;
+pub mod search;
+
+pub use scanner::Scanner;
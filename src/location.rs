@@ -0,0 +1,63 @@
+/// A contiguous range of positions within a [`Scanner`](crate::Scanner)'s
+/// source.
+///
+/// # Example
+///
+/// ```
+/// use snob::location::Span;
+///
+/// let span = Span::new(3, 7);
+/// assert_eq!(span.len(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+	/// The position of the first character in the span.
+	pub start: usize,
+	/// The position just after the last character in the span.
+	pub end: usize,
+}
+
+impl Span {
+	/// Create a new [`Span`] spanning `[start, end)`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::location::Span;
+	///
+	/// let span = Span::new(3, 7);
+	/// assert_eq!(span.start, 3);
+	/// assert_eq!(span.end, 7);
+	/// ```
+	pub fn new(start: usize, end: usize) -> Self {
+		Self { start, end }
+	}
+
+	/// The number of positions covered by the span.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::location::Span;
+	///
+	/// let span = Span::new(3, 7);
+	/// assert_eq!(span.len(), 4);
+	/// ```
+	pub fn len(&self) -> usize {
+		self.end - self.start
+	}
+
+	/// Returns `true` if the span covers no positions.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::location::Span;
+	///
+	/// let span = Span::new(3, 3);
+	/// assert!(span.is_empty());
+	/// ```
+	pub fn is_empty(&self) -> bool {
+		self.start == self.end
+	}
+}
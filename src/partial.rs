@@ -0,0 +1,166 @@
+use crate::pattern::Pattern;
+use crate::Scanner;
+
+/// How many more characters are needed to decide a match, when known.
+///
+/// This mirrors winnow's `Needed` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Needed {
+	/// The exact number of additional characters required.
+	Size(usize),
+	/// An unbounded number of additional characters may be required.
+	Unknown,
+}
+
+/// The result of attempting a match against a [`PartialScanner`].
+///
+/// This mirrors winnow's `Partial`/`Needed` streaming model: unlike a plain
+/// `Option`, it distinguishes a definite non-match from input that is merely
+/// incomplete, letting a caller buffer more input and retry instead of
+/// failing at a chunk boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scan<T> {
+	/// The pattern matched.
+	Match(T),
+	/// The pattern definitely does not match, regardless of what input
+	/// follows.
+	NoMatch,
+	/// The remainder of the source is a proper prefix of what the pattern
+	/// needs. More input may turn this into a [`Scan::Match`].
+	Incomplete(Needed),
+}
+
+/// A [`Scanner`] over input that may have more characters arriving later.
+///
+/// Its matching methods return a three-valued [`Scan`] instead of an
+/// `Option`, so a tokenizer lexing data that arrives in chunks can tell a
+/// chunk boundary (buffer more and retry) apart from a genuine non-match.
+///
+/// # Example
+///
+/// ```
+/// use snob::partial::{PartialScanner, Scan};
+///
+/// let scanner = PartialScanner::new("Hel");
+/// assert_eq!(scanner.starts_with("Hello"), Scan::Incomplete(snob::partial::Needed::Size(2)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PartialScanner {
+	scanner: Scanner,
+}
+
+impl PartialScanner {
+	/// Create a new [`PartialScanner`] with a given (possibly incomplete)
+	/// source.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::partial::PartialScanner;
+	///
+	/// let scanner = PartialScanner::new("Hello, world!");
+	/// ```
+	pub fn new(source: impl AsRef<str>) -> Self {
+		Self {
+			scanner: Scanner::new(source),
+		}
+	}
+
+	/// Get the underlying [`Scanner`].
+	pub fn scanner(&self) -> &Scanner {
+		&self.scanner
+	}
+
+	/// Consume the [`PartialScanner`], returning the underlying [`Scanner`].
+	pub fn into_scanner(self) -> Scanner {
+		self.scanner
+	}
+
+	/// Appends more characters to the end of the source, without changing
+	/// the current position. Use this after a [`Scan::Incomplete`] result to
+	/// buffer more input before retrying the same match.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::partial::{PartialScanner, Scan};
+	///
+	/// let mut scanner = PartialScanner::new("Hel");
+	/// assert_eq!(scanner.starts_with("Hello"), Scan::Incomplete(snob::partial::Needed::Size(2)));
+	/// scanner.feed("lo");
+	/// assert_eq!(scanner.starts_with("Hello"), Scan::Match(5));
+	/// ```
+	pub fn feed(&mut self, more: impl AsRef<str>) {
+		self.scanner.feed(more);
+	}
+
+	/// If `source[position..]` starts with the given `pattern`, then this
+	/// returns the position just after the match. If it definitely does not,
+	/// [`Scan::NoMatch`] is returned. If the remainder of the source is a
+	/// proper prefix of what `pattern` needs, [`Scan::Incomplete`] is
+	/// returned with the number of characters still needed, when known.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::partial::{PartialScanner, Scan};
+	///
+	/// let scanner = PartialScanner::new("Hello, world!");
+	/// assert_eq!(scanner.starts_with("Hello"), Scan::Match(5));
+	/// ```
+	pub fn starts_with(&self, pattern: impl Pattern) -> Scan<usize> {
+		pattern.is_prefix_of_partial(self.scanner.source(), self.scanner.position())
+	}
+
+	/// If the next character in the scanner matches the given `pattern`,
+	/// then this consumes the longest initial run of matches and returns the
+	/// position just after it. If the source runs out while still matching,
+	/// [`Scan::Incomplete`] is returned, since a longer run may continue once
+	/// more input arrives.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::csets::AsciiLetters;
+	/// use snob::partial::{PartialScanner, Scan};
+	///
+	/// let scanner = PartialScanner::new("Hello, world!");
+	/// assert_eq!(scanner.many(AsciiLetters), Scan::Match(5));
+	/// ```
+	pub fn many(&self, pattern: impl Pattern) -> Scan<usize> {
+		let source = self.scanner.source();
+		let position = self.scanner.position();
+
+		let mut i = match pattern.is_prefix_of_partial(source, position) {
+			Scan::Match(end) => end,
+			other => return other,
+		};
+
+		loop {
+			match pattern.is_prefix_of_partial(source, i) {
+				Scan::Match(next) if next > i => i = next,
+				Scan::Match(_) | Scan::NoMatch => break,
+				Scan::Incomplete(_) => return Scan::Incomplete(Needed::Unknown),
+			}
+		}
+
+		Scan::Match(i)
+	}
+
+	/// Looks for the given `pattern` in the remainder of the scanner, and
+	/// returns the position of the first character of the match. If the
+	/// source runs out before one is found, [`Scan::Incomplete`] is
+	/// returned, since one may appear once more input arrives.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::partial::{PartialScanner, Scan};
+	///
+	/// let scanner = PartialScanner::new("Hello, world!");
+	/// assert_eq!(scanner.upto(' '), Scan::Match(6));
+	/// ```
+	pub fn upto(&self, pattern: impl Pattern) -> Scan<usize> {
+		pattern.find_in_partial(self.scanner.source(), self.scanner.position())
+	}
+}
@@ -0,0 +1,402 @@
+use std::collections::HashSet;
+
+use crate::csets::{
+	Alphabetic, Alphanumeric, AnyCharacter, Ascii, AsciiDigits, AsciiLetters, AsciiLowercase,
+	AsciiUppercase, CharRange, CharacterSet, CharacterSetComplement, CharacterSetDifference,
+	CharacterSetIntersection, CharacterSetUnion, CompiledSet, Control, Numeric, Whitespace,
+};
+use crate::partial::{Needed, Scan};
+use crate::search::TwoWaySearcher;
+
+/// A pattern that a [`Scanner`](crate::Scanner) can search for.
+///
+/// This mirrors the `Pattern`/`Searcher` split in Rust's standard library,
+/// letting the same scanning methods accept a [`char`], a string, a slice of
+/// characters, a [`CharacterSet`], or a closure. A [`char`], a `&str`, and a
+/// `&[char]` match themselves literally, while a [`CharacterSet`] (and a
+/// closure) match a single character that it contains.
+///
+/// Every [`CharacterSet`] in [`csets`](crate::csets) implements `Pattern`.
+/// A [`CharacterSet`] defined outside this crate does not, unless it also
+/// implements `Pattern` itself.
+///
+/// # Example
+///
+/// ```
+/// use snob::pattern::Pattern;
+///
+/// assert_eq!("Hello".is_prefix_of(&['H', 'i'], 0), None);
+/// ```
+pub trait Pattern {
+	/// If `source[at..]` starts with this pattern, returns the position just
+	/// after the match. Otherwise, `None` is returned.
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize>;
+
+	/// Looks for this pattern in `source[from..]`, returning the position of
+	/// the first character of the match. Otherwise, `None` is returned.
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize>;
+
+	/// Like [`is_prefix_of`](Pattern::is_prefix_of), but for a
+	/// [`PartialScanner`](crate::partial::PartialScanner) whose source may
+	/// still be incomplete: instead of collapsing "the pattern doesn't
+	/// match" and "we ran out of source before we could tell" into the same
+	/// `None`, this tells them apart with a [`Scan`].
+	///
+	/// The default implementation is correct for any pattern that only ever
+	/// inspects the single character at `at`, such as a [`CharacterSet`] or a
+	/// closure. Patterns spanning more than one character, like `&str` and
+	/// `&[char]`, override it to report how many more characters are needed.
+	fn is_prefix_of_partial(&self, source: &[char], at: usize) -> Scan<usize> {
+		match self.is_prefix_of(source, at) {
+			Some(end) => Scan::Match(end),
+			None if at >= source.len() => Scan::Incomplete(Needed::Size(1)),
+			None => Scan::NoMatch,
+		}
+	}
+
+	/// Like [`find_in`](Pattern::find_in), but for a
+	/// [`PartialScanner`](crate::partial::PartialScanner): not finding a
+	/// match yet doesn't rule one out once more input arrives, so this never
+	/// returns [`Scan::NoMatch`].
+	fn find_in_partial(&self, source: &[char], from: usize) -> Scan<usize> {
+		match self.find_in(source, from) {
+			Some(start) => Scan::Match(start),
+			None => Scan::Incomplete(Needed::Unknown),
+		}
+	}
+
+	/// Converts this pattern into an equivalent one that is cheaper to probe
+	/// repeatedly, such as from [`Matches`](crate::iter::Matches) or
+	/// [`MatchIndices`](crate::iter::MatchIndices), which call [`find_in`](Pattern::find_in)
+	/// once per match.
+	///
+	/// The default implementation just returns `self`, which is already the
+	/// case for a pattern like a [`CharacterSet`] or a closure, where each
+	/// call only inspects one character. `&str` and `&[char]` override it to
+	/// precompute their [`TwoWaySearcher`] once, instead of redoing its
+	/// critical factorization on every call.
+	fn into_reusable(self) -> impl Pattern
+	where
+		Self: Sized,
+	{
+		self
+	}
+}
+
+/// Tests whether the character at `at` is contained in `cset`, mirroring
+/// [`Pattern::is_prefix_of`] for any [`CharacterSet`].
+fn cset_is_prefix_of(cset: &impl CharacterSet, source: &[char], at: usize) -> Option<usize> {
+	cset.contains(*source.get(at)?).then_some(at + 1)
+}
+
+/// Finds the first character in `source[from..]` contained in `cset`,
+/// mirroring [`Pattern::find_in`] for any [`CharacterSet`].
+fn cset_find_in(cset: &impl CharacterSet, source: &[char], from: usize) -> Option<usize> {
+	(from..source.len()).find(|&i| cset.contains(source[i]))
+}
+
+impl Pattern for char {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		(*source.get(at)? == *self).then_some(at + 1)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		Some(source.get(from..)?.iter().position(|ch| ch == self)? + from)
+	}
+}
+
+impl Pattern for &str {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		let mut i = at;
+		for pattern_char in self.chars() {
+			if *source.get(i)? != pattern_char {
+				return None;
+			}
+			i += 1;
+		}
+
+		Some(i)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		let needle: Vec<char> = self.chars().collect();
+		TwoWaySearcher::new(&needle).search(source, &needle, from)
+	}
+
+	fn is_prefix_of_partial(&self, source: &[char], at: usize) -> Scan<usize> {
+		let mut i = at;
+		for pattern_char in self.chars() {
+			match source.get(i) {
+				Some(&ch) if ch == pattern_char => i += 1,
+				Some(_) => return Scan::NoMatch,
+				None => return Scan::Incomplete(Needed::Size(self.chars().count() - (i - at))),
+			}
+		}
+
+		Scan::Match(i)
+	}
+
+	fn into_reusable(self) -> impl Pattern {
+		ReusablePattern::new(self.chars().collect())
+	}
+}
+
+impl Pattern for &[char] {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		let end = at.checked_add(self.len())?;
+		(source.get(at..end)? == *self).then_some(end)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		TwoWaySearcher::new(self).search(source, self, from)
+	}
+
+	fn is_prefix_of_partial(&self, source: &[char], at: usize) -> Scan<usize> {
+		let end = at.saturating_add(self.len());
+		match source.get(at..end) {
+			Some(slice) if slice == *self => Scan::Match(end),
+			Some(_) => Scan::NoMatch,
+			None => {
+				let available = source.get(at..).unwrap_or(&[]);
+				if <[char]>::starts_with(self, available) {
+					Scan::Incomplete(Needed::Size(self.len() - available.len()))
+				} else {
+					Scan::NoMatch
+				}
+			}
+		}
+	}
+
+	fn into_reusable(self) -> impl Pattern {
+		ReusablePattern::new(self.to_vec())
+	}
+}
+
+/// A literal `&str` or `&[char]` pattern whose [`TwoWaySearcher`] has already
+/// been computed, so that repeated [`Pattern::find_in`] calls against it --
+/// such as the ones [`Matches`](crate::iter::Matches) and
+/// [`MatchIndices`](crate::iter::MatchIndices) make once per match -- don't
+/// redo the needle's critical factorization every time.
+///
+/// This is created by [`Pattern::into_reusable`].
+///
+/// # Example
+///
+/// ```
+/// use snob::pattern::Pattern;
+///
+/// let pattern = "world".into_reusable();
+/// assert_eq!(pattern.find_in(&['w', 'o', 'r', 'l', 'd'], 0), Some(0));
+/// ```
+pub struct ReusablePattern {
+	needle: Vec<char>,
+	searcher: TwoWaySearcher,
+}
+
+impl ReusablePattern {
+	fn new(needle: Vec<char>) -> Self {
+		let searcher = TwoWaySearcher::new(&needle);
+		Self { needle, searcher }
+	}
+}
+
+impl Pattern for ReusablePattern {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		let end = at.checked_add(self.needle.len())?;
+		(source.get(at..end)? == self.needle.as_slice()).then_some(end)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		self.searcher.search(source, &self.needle, from)
+	}
+}
+
+impl<F: Fn(char) -> bool> Pattern for F {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		self(*source.get(at)?).then_some(at + 1)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		Some(source.get(from..)?.iter().position(|&ch| self(ch))? + from)
+	}
+}
+
+impl Pattern for AnyCharacter {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for Ascii {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for AsciiDigits {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for AsciiLowercase {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for AsciiUppercase {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for AsciiLetters {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for CharRange {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for Alphanumeric {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for Whitespace {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for Alphabetic {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for Numeric {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for Control {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for CompiledSet {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl Pattern for HashSet<char> {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl<A: CharacterSet, B: CharacterSet> Pattern for CharacterSetUnion<A, B> {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl<A: CharacterSet, B: CharacterSet> Pattern for CharacterSetIntersection<A, B> {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl<A: CharacterSet, B: CharacterSet> Pattern for CharacterSetDifference<A, B> {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
+
+impl<Inner: CharacterSet> Pattern for CharacterSetComplement<Inner> {
+	fn is_prefix_of(&self, source: &[char], at: usize) -> Option<usize> {
+		cset_is_prefix_of(self, source, at)
+	}
+
+	fn find_in(&self, source: &[char], from: usize) -> Option<usize> {
+		cset_find_in(self, source, from)
+	}
+}
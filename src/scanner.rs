@@ -1,4 +1,16 @@
 use crate::csets::CharacterSet;
+use crate::iter::{Lines, MatchIndices, Matches, Split};
+use crate::pattern::Pattern;
+
+/// Find the position of every `\n` in `source`, for [`Scanner::line_col`].
+fn newline_indices(source: &[char]) -> Box<[usize]> {
+	source
+		.iter()
+		.enumerate()
+		.filter(|&(_, &ch)| ch == '\n')
+		.map(|(i, _)| i)
+		.collect()
+}
 
 /// This is used to analyze string. It can be initialized using either
 /// [`Scanner::from`] or [`Scanner::new`].
@@ -17,6 +29,7 @@ use crate::csets::CharacterSet;
 pub struct Scanner {
 	source: Box<[char]>,
 	position: usize,
+	newlines: Box<[usize]>,
 }
 
 impl Scanner {
@@ -30,9 +43,12 @@ impl Scanner {
 	/// let scanner = Scanner::new("Hello, world!");
 	/// ```
 	pub fn new(source: impl AsRef<str>) -> Self {
+		let source: Box<[char]> = source.as_ref().chars().collect();
+		let newlines = newline_indices(&source);
 		Self {
-			source: source.as_ref().chars().collect(),
+			source,
 			position: 0,
+			newlines,
 		}
 	}
 
@@ -182,9 +198,9 @@ impl Scanner {
 		self.goto(position)
 	}
 
-	/// Looks for the given `substring` in the remainder of the scanner. If the
-	/// substring is found, the position of the first character in the
-	/// substring is returned. Otherwise, `None` is returned.
+	/// Looks for the given `pattern` in the remainder of the scanner. If the
+	/// pattern is found, the position of the first character of the match is
+	/// returned. Otherwise, `None` is returned.
 	///
 	/// # Example
 	///
@@ -197,16 +213,13 @@ impl Scanner {
 	/// assert_eq!(position, 3);
 	/// # Some(())
 	/// # }
-	pub fn find_substring(&self, substring: impl AsRef<str>) -> Option<usize> {
-		self.source
-			.get(self.position..)?
-			.iter()
-			.collect::<String>()
-			.find(substring.as_ref())
+	pub fn find_substring(&self, pattern: impl Pattern) -> Option<usize> {
+		pattern.find_in(&self.source, self.position)
 	}
 
-	/// If `source[position..]` starts with the given string, then this returns
-	/// the ending position of the substring. Otherwise, `None` is returned.
+	/// If `source[position..]` starts with the given `pattern`, then this
+	/// returns the position just after the match. Otherwise, `None` is
+	/// returned.
 	///
 	/// # Example
 	///
@@ -220,21 +233,13 @@ impl Scanner {
 	/// # Some(())
 	/// # }
 	/// ```
-	pub fn starts_with(&self, substring: impl AsRef<str>) -> Option<usize> {
-		let mut i = self.position;
-		for substring_char in substring.as_ref().chars() {
-			if *self.source.get(i)? != substring_char {
-				return None;
-			}
-			i += 1;
-		}
-
-		Some(i)
+	pub fn starts_with(&self, pattern: impl Pattern) -> Option<usize> {
+		pattern.is_prefix_of(&self.source, self.position)
 	}
 
-	/// If `source[position..]` starts with the given string, then this returns
-	/// a copy of the substring. Otherwise, `None` is returned. This is the
-	/// equivalent of: `self.goto(self.starts_with(substring)?)`.
+	/// If `source[position..]` starts with the given `pattern`, then this
+	/// returns a copy of the match. Otherwise, `None` is returned. This is
+	/// the equivalent of: `self.goto(self.starts_with(pattern)?)`.
 	///
 	/// # Example
 	///
@@ -249,14 +254,23 @@ impl Scanner {
 	/// # Some(())
 	/// # }
 	/// ```
-	pub fn advance_if_starts_with(&mut self, substring: impl AsRef<str>) -> Option<String> {
-		let position = self.starts_with(substring)?;
+	pub fn advance_if_starts_with(&mut self, pattern: impl Pattern) -> Option<String> {
+		let position = self.starts_with(pattern)?;
 		self.goto(position)
 	}
 
-	/// If the next character in the scanner is contained in the given `cset`,
-	/// then the position after the next character is returned. Otherwise,
-	/// `None` is returned.
+	/// If `source[position..]` starts with the given `pattern`, then the
+	/// position just after the match is returned. Otherwise, `None` is
+	/// returned.
+	///
+	/// This is an alias of [`starts_with`](Scanner::starts_with), kept
+	/// distinct for callers whose `pattern` conceptually matches a single
+	/// character, such as a [`CharacterSet`], a [`char`], or a closure. For
+	/// those, this matches the next single character. A multi-character
+	/// `&str` or `&[char]`, though, still matches that literal sequence in
+	/// full, the same as `starts_with` -- not any one of its characters. To
+	/// match any single character out of a set, pass a [`CharacterSet`]
+	/// instead.
 	///
 	/// # Example
 	///
@@ -270,14 +284,20 @@ impl Scanner {
 	/// # Some(())
 	/// # }
 	/// ```
-	pub fn any(&self, cset: impl CharacterSet) -> Option<usize> {
-		cset.contains(*self.source.get(self.position)?)
-			.then_some(self.position + 1)
+	pub fn any(&self, pattern: impl Pattern) -> Option<usize> {
+		self.starts_with(pattern)
 	}
 
-	/// If the next character in the scanner is contained in the given `cset`,
-	/// then the position after the longest initial sequence of characters in
-	/// `cset` is returned. Otherwise, `None` is returned.
+	/// Repeatedly matches the given `pattern` starting at `position`, and
+	/// returns the position just after the longest run of consecutive
+	/// matches. Otherwise, `None` is returned. For a [`CharacterSet`], this
+	/// is the position after the longest initial sequence of characters in
+	/// the set.
+	///
+	/// A multi-character `&str` or `&[char]` matches that literal sequence
+	/// repeated back to back, not any one of its characters repeated; for
+	/// the latter, pass a [`CharacterSet`] instead, e.g.
+	/// `' '.union('\t')` rather than `" \t"`.
 	///
 	/// # Example
 	///
@@ -292,22 +312,26 @@ impl Scanner {
 	/// # Some(())
 	/// # }
 	/// ```
-	pub fn many(&self, cset: impl CharacterSet) -> Option<usize> {
-		if !cset.contains(*self.source.get(self.position)?) {
-			return None;
-		}
-
-		let mut i = self.position;
-		while i < self.source.len() && cset.contains(self.source[i]) {
-			i += 1;
+	pub fn many(&self, pattern: impl Pattern) -> Option<usize> {
+		let mut i = pattern.is_prefix_of(&self.source, self.position)?;
+		while let Some(next) = pattern.is_prefix_of(&self.source, i) {
+			if next <= i {
+				break;
+			}
+			i = next;
 		}
 
 		Some(i)
 	}
 
-	/// If the remainder of the scanner contains a character from the given
-	/// `cset`, then the position of the aforementioned character is returned.
-	/// Otherwise, `None` is returned.
+	/// Looks for the given `pattern` in the remainder of the scanner, and
+	/// returns the position of the first character of the match. Otherwise,
+	/// `None` is returned. For a [`CharacterSet`], this is the position of
+	/// the next character contained in the set.
+	///
+	/// A multi-character `&str` or `&[char]` is searched for as a literal
+	/// sequence, not as a set of characters to look for individually; for
+	/// the latter, pass a [`CharacterSet`] instead.
 	///
 	/// # Example
 	///
@@ -321,14 +345,265 @@ impl Scanner {
 	/// # Some(())
 	/// # }
 	/// ```
-	pub fn upto(&self, cset: impl CharacterSet) -> Option<usize> {
+	pub fn upto(&self, pattern: impl Pattern) -> Option<usize> {
+		pattern.find_in(&self.source, self.position)
+	}
+
+	/// If `source[..position]` ends with the given string, then this returns
+	/// the starting position of the substring. Otherwise, `None` is returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::Scanner;
+	///
+	/// let mut scanner = Scanner::new("Hello, world!");
+	/// scanner.goto(5);
+	/// let position = scanner.ends_with("Hello").unwrap();
+	/// assert_eq!(position, 0);
+	/// ```
+	pub fn ends_with(&self, substring: impl AsRef<str>) -> Option<usize> {
+		let substring = substring.as_ref();
+		let start = self.position.checked_sub(substring.chars().count())?;
+
+		for (i, substring_char) in (start..).zip(substring.chars()) {
+			if *self.source.get(i)? != substring_char {
+				return None;
+			}
+		}
+
+		Some(start)
+	}
+
+	/// Looks for the given `substring` in `source[..position]`, returning the
+	/// position of the first character of its last occurrence. Otherwise,
+	/// `None` is returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::Scanner;
+	///
+	/// let mut scanner = Scanner::new("Hello, world!");
+	/// scanner.goto(scanner.len());
+	/// let position = scanner.rfind_substring("l").unwrap();
+	/// assert_eq!(position, 10);
+	/// ```
+	pub fn rfind_substring(&self, substring: impl AsRef<str>) -> Option<usize> {
+		let needle: Vec<char> = substring.as_ref().chars().collect();
+		let haystack = self.source.get(..self.position)?;
+		let last_start = haystack.len().checked_sub(needle.len())?;
+		(0..=last_start)
+			.rev()
+			.find(|&i| haystack[i..i + needle.len()] == needle[..])
+	}
+
+	/// If the character immediately before `position` is contained in the
+	/// given `cset`, then the starting position of the longest run of `cset`
+	/// characters immediately before `position` is returned. Otherwise,
+	/// `None` is returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::Scanner;
+	/// use snob::csets::AsciiLetters;
+	///
+	/// let mut scanner = Scanner::new("Hello, world!");
+	/// scanner.goto(5);
+	/// let position = scanner.many_back(AsciiLetters).unwrap();
+	/// assert_eq!(position, 0);
+	/// ```
+	pub fn many_back(&self, cset: impl CharacterSet) -> Option<usize> {
+		if self.position == 0 || !cset.contains(*self.source.get(self.position - 1)?) {
+			return None;
+		}
+
 		let mut i = self.position;
-		while !cset.contains(*self.source.get(i)?) {
-			i += 1;
+		while i > 0 && cset.contains(self.source[i - 1]) {
+			i -= 1;
 		}
 
 		Some(i)
 	}
+
+	/// Walking backward from `position`, looks for a character from the given
+	/// `cset`. If one is found, its position is returned. Otherwise, `None`
+	/// is returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::Scanner;
+	///
+	/// let mut scanner = Scanner::new("Hello, world!");
+	/// scanner.goto(7);
+	/// let position = scanner.upto_back(' ').unwrap();
+	/// assert_eq!(position, 6);
+	/// ```
+	pub fn upto_back(&self, cset: impl CharacterSet) -> Option<usize> {
+		let mut i = self.position;
+		while i > 0 {
+			i -= 1;
+			if cset.contains(self.source[i]) {
+				return Some(i);
+			}
+		}
+
+		None
+	}
+
+	/// Returns an iterator over the non-overlapping matches of `pattern` in
+	/// the remainder of the scanner, yielding the matched substrings.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::Scanner;
+	///
+	/// let scanner = Scanner::new("abcabcabc");
+	/// let matches: Vec<_> = scanner.matches("abc").collect();
+	/// assert_eq!(matches, vec!["abc", "abc", "abc"]);
+	/// ```
+	pub fn matches(&self, pattern: impl Pattern) -> Matches<'_, impl Pattern> {
+		Matches {
+			source: &self.source,
+			pattern: pattern.into_reusable(),
+			position: self.position,
+		}
+	}
+
+	/// Returns an iterator over the non-overlapping matches of `pattern` in
+	/// the remainder of the scanner, yielding the [`Span`](crate::location::Span)
+	/// of each match alongside the matched substring.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::location::Span;
+	/// use snob::Scanner;
+	///
+	/// let scanner = Scanner::new("Hello, world!");
+	/// let matches: Vec<_> = scanner.match_indices('o').collect();
+	/// assert_eq!(
+	///     matches,
+	///     vec![(Span::new(4, 5), "o".to_string()), (Span::new(8, 9), "o".to_string())]
+	/// );
+	/// ```
+	pub fn match_indices(&self, pattern: impl Pattern) -> MatchIndices<'_, impl Pattern> {
+		MatchIndices {
+			source: &self.source,
+			pattern: pattern.into_reusable(),
+			position: self.position,
+		}
+	}
+
+	/// Returns an iterator over the substrings of the remainder of the
+	/// scanner that are separated by runs of characters in `cset`, yielding
+	/// the [`Span`](crate::location::Span) of each substring alongside the
+	/// substring itself.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::location::Span;
+	/// use snob::Scanner;
+	///
+	/// let scanner = Scanner::new("one two  three");
+	/// let words: Vec<_> = scanner.split(' ').collect();
+	/// assert_eq!(
+	///     words,
+	///     vec![
+	///         (Span::new(0, 3), "one".to_string()),
+	///         (Span::new(4, 7), "two".to_string()),
+	///         (Span::new(9, 14), "three".to_string()),
+	///     ]
+	/// );
+	/// ```
+	pub fn split(&self, cset: impl CharacterSet) -> Split<'_, impl CharacterSet> {
+		Split {
+			source: &self.source,
+			cset,
+			position: Some(self.position),
+		}
+	}
+
+	/// Returns an iterator over the lines of the remainder of the scanner,
+	/// split on `\r\n` or `\n`, yielding the [`Span`](crate::location::Span)
+	/// of each line alongside the line itself.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::location::Span;
+	/// use snob::Scanner;
+	///
+	/// let scanner = Scanner::new("one\ntwo\r\nthree");
+	/// let lines: Vec<_> = scanner.lines().collect();
+	/// assert_eq!(
+	///     lines,
+	///     vec![
+	///         (Span::new(0, 3), "one".to_string()),
+	///         (Span::new(4, 7), "two".to_string()),
+	///         (Span::new(9, 14), "three".to_string()),
+	///     ]
+	/// );
+	/// ```
+	pub fn lines(&self) -> Lines<'_> {
+		Lines {
+			source: &self.source,
+			position: Some(self.position),
+		}
+	}
+
+	/// Appends more characters to the end of the source, without changing
+	/// the current `position`. This is primarily used by
+	/// [`PartialScanner`](crate::partial::PartialScanner) to grow an
+	/// incomplete source as more input arrives.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::Scanner;
+	///
+	/// let mut scanner = Scanner::new("Hel");
+	/// scanner.feed("lo, world!");
+	/// assert_eq!(scanner.source().iter().collect::<String>(), "Hello, world!");
+	/// ```
+	pub fn feed(&mut self, more: impl AsRef<str>) {
+		let offset = self.source.len();
+		let mut source = std::mem::take(&mut self.source).into_vec();
+		source.extend(more.as_ref().chars());
+		self.source = source.into_boxed_slice();
+
+		let mut newlines = std::mem::take(&mut self.newlines).into_vec();
+		newlines.extend(newline_indices(&self.source[offset..]).iter().map(|&i| i + offset));
+		self.newlines = newlines.into_boxed_slice();
+	}
+
+	/// Converts a `position` into a 1-based line number and a 0-based column
+	/// number.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::Scanner;
+	///
+	/// let scanner = Scanner::new("one\ntwo\nthree");
+	/// assert_eq!(scanner.line_col(0), (1, 0));
+	/// assert_eq!(scanner.line_col(4), (2, 0));
+	/// assert_eq!(scanner.line_col(8), (3, 0));
+	/// assert_eq!(scanner.line_col(11), (3, 3));
+	/// ```
+	pub fn line_col(&self, position: usize) -> (usize, usize) {
+		let newlines_before = self.newlines.partition_point(|&n| n < position);
+		let line_start = match newlines_before {
+			0 => 0,
+			n => self.newlines[n - 1] + 1,
+		};
+
+		(newlines_before + 1, position - line_start)
+	}
 }
 
 impl From<&str> for Scanner {
@@ -339,9 +614,11 @@ impl From<&str> for Scanner {
 
 impl From<Box<[char]>> for Scanner {
 	fn from(value: Box<[char]>) -> Self {
+		let newlines = newline_indices(&value);
 		Self {
 			source: value,
 			position: 0,
+			newlines,
 		}
 	}
 }
@@ -0,0 +1,195 @@
+use std::cmp::Ordering;
+
+/// A substring searcher over `[char]` that finds a needle in a haystack in
+/// O(n) time with O(1) extra space, using the Two-Way string-matching
+/// algorithm (the same one `str::find` uses internally).
+///
+/// Unlike collecting the haystack into a `String` and calling [`str::find`],
+/// this runs directly over the char slice with no allocation, which matters
+/// when a caller probes the same [`Scanner`](crate::Scanner) repeatedly, as
+/// a tokenizer does.
+///
+/// # Example
+///
+/// ```
+/// use snob::search::TwoWaySearcher;
+///
+/// let haystack: Vec<char> = "Hello, world!".chars().collect();
+/// let needle: Vec<char> = "world".chars().collect();
+/// let searcher = TwoWaySearcher::new(&needle);
+/// assert_eq!(searcher.search(&haystack, &needle, 0), Some(7));
+/// ```
+pub struct TwoWaySearcher {
+	/// The start of the needle's critical factorization, `needle[..l]`
+	/// being the left part and `needle[l..]` being the right part.
+	critical_pos: usize,
+	/// How far to shift the needle once its right part has matched but its
+	/// left part has not: the period of the right part of the critical
+	/// factorization when that is also a period of the whole needle, and
+	/// `max(critical_pos, needle.len() - critical_pos) + 1` otherwise.
+	period: usize,
+	/// `true` when the right part's period is not a period of the whole
+	/// needle (including whenever `critical_pos > needle.len() - period`), in
+	/// which case the memory optimization below does not apply.
+	long_period: bool,
+}
+
+impl TwoWaySearcher {
+	/// Precompute the critical factorization of `needle`, so that it can be
+	/// searched for repeatedly without redoing this work.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::search::TwoWaySearcher;
+	///
+	/// let needle: Vec<char> = "world".chars().collect();
+	/// let searcher = TwoWaySearcher::new(&needle);
+	/// ```
+	pub fn new(needle: &[char]) -> Self {
+		if needle.is_empty() {
+			return Self {
+				critical_pos: 0,
+				period: 0,
+				long_period: false,
+			};
+		}
+
+		let (critical_pos, period) = critical_factorization(needle);
+
+		// The critical factorization theorem only lets us reuse `period` as
+		// a period of the whole needle (not just its suffix) when it
+		// actually repeats across the boundary; otherwise fall back to a
+		// coarser shift that doesn't rely on periodicity.
+		let is_short_period = critical_pos + period <= needle.len()
+			&& needle[..critical_pos] == needle[period..period + critical_pos];
+
+		if is_short_period {
+			Self {
+				critical_pos,
+				period,
+				long_period: false,
+			}
+		} else {
+			Self {
+				critical_pos,
+				period: critical_pos.max(needle.len() - critical_pos) + 1,
+				long_period: true,
+			}
+		}
+	}
+
+	/// Find the first occurrence of `needle` in `haystack[from..]`, returning
+	/// the absolute position of the first character of the match.
+	///
+	/// `needle` must be the same slice this searcher was constructed from.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use snob::search::TwoWaySearcher;
+	///
+	/// let haystack: Vec<char> = "Hello, world!".chars().collect();
+	/// let needle: Vec<char> = "world".chars().collect();
+	/// let searcher = TwoWaySearcher::new(&needle);
+	/// assert_eq!(searcher.search(&haystack, &needle, 0), Some(7));
+	/// ```
+	pub fn search(&self, haystack: &[char], needle: &[char], from: usize) -> Option<usize> {
+		if needle.is_empty() {
+			return (from <= haystack.len()).then_some(from);
+		}
+
+		let mut pos = from;
+		let mut memory = 0;
+
+		while pos + needle.len() <= haystack.len() {
+			// Compare the right part, `needle[critical_pos..]`, left to
+			// right, resuming after whatever `memory` already verified.
+			let mut i = self.critical_pos.max(memory);
+			while i < needle.len() && needle[i] == haystack[pos + i] {
+				i += 1;
+			}
+
+			if i < needle.len() {
+				pos += i - self.critical_pos + 1;
+				if !self.long_period {
+					memory = 0;
+				}
+				continue;
+			}
+
+			// The right part matches; compare the left part,
+			// `needle[..critical_pos]`, left to right, starting past
+			// whatever `memory` already verified.
+			let mut j = memory;
+			while j < self.critical_pos && needle[j] == haystack[pos + j] {
+				j += 1;
+			}
+
+			if j >= self.critical_pos {
+				return Some(pos);
+			}
+
+			pos += self.period;
+			if !self.long_period {
+				memory = needle.len() - self.period;
+			}
+		}
+
+		None
+	}
+}
+
+/// Find the critical position `l` and period `p` of `needle`'s critical
+/// factorization: the maximal suffix under `<=` and under `>=`, taking
+/// whichever starts later.
+fn critical_factorization(needle: &[char]) -> (usize, usize) {
+	let (i, period_i) = maximal_suffix(needle, false);
+	let (j, period_j) = maximal_suffix(needle, true);
+
+	if i > j {
+		(i, period_i)
+	} else {
+		(j, period_j)
+	}
+}
+
+/// Find the position and period of the maximal suffix of `arr`, ordering
+/// characters by `<` normally, or by `>` when `reverse` is set (giving the
+/// maximal suffix under `<=` and `>=` respectively).
+fn maximal_suffix(arr: &[char], reverse: bool) -> (usize, usize) {
+	let mut left = 0;
+	let mut right = 1;
+	let mut offset = 0;
+	let mut period = 1;
+
+	while right + offset < arr.len() {
+		let a = arr[right + offset];
+		let b = arr[left + offset];
+		let ordering = if reverse { b.cmp(&a) } else { a.cmp(&b) };
+
+		match ordering {
+			Ordering::Less => {
+				right += offset + 1;
+				offset = 0;
+				period = right - left;
+			}
+			Ordering::Equal => {
+				if offset + 1 == period {
+					right += period;
+					offset = 0;
+				} else {
+					offset += 1;
+				}
+			}
+			Ordering::Greater => {
+				left = right;
+				right += 1;
+				offset = 0;
+				period = 1;
+			}
+		}
+	}
+
+	(left, period)
+}